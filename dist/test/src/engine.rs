@@ -0,0 +1,369 @@
+//! Platform-agnostic todo engine.
+//!
+//! This module holds the pure data logic — [`TodoItem`], [`TodoList`] and
+//! the add/toggle/remove/edit/remaining operations — with no dependency on
+//! `leptos`. The same engine therefore powers the WASM UI and, through the
+//! [`TodoListHandle`] object exported below, the UniFFI bindings consumed
+//! from Kotlin/Swift/Python.
+
+use std::sync::{Arc, RwLock};
+
+use serde::{Deserialize, Serialize};
+use crate::UniffiCustomTypeConverter;
+use uuid::Uuid;
+
+/// Represents a todo item in our application
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, uniffi::Record)]
+pub struct TodoItem {
+    pub id: Uuid,
+    pub title: String,
+    pub completed: bool,
+}
+
+/// State management for our todo list
+#[derive(Clone, Debug, Default)]
+pub struct TodoList {
+    pub(crate) items: Vec<TodoItem>,
+}
+
+impl TodoList {
+    /// Creates a new empty todo list
+    #[inline]
+    pub fn new() -> Self {
+        TodoList { items: Vec::new() }
+    }
+
+    /// Adds a new todo item to the list
+    ///
+    /// # Arguments
+    /// * `title` - The title of the todo item
+    ///
+    /// # Returns
+    /// The newly created todo item
+    pub fn add_item(&mut self, title: String) -> TodoItem {
+        let item = TodoItem {
+            id: Uuid::new_v4(),
+            title,
+            completed: false,
+        };
+        self.items.push(item.clone());
+        item
+    }
+
+    /// Toggles the `completed` flag of the item with the given id
+    pub fn toggle(&mut self, id: Uuid) {
+        if let Some(item) = self.items.iter_mut().find(|item| item.id == id) {
+            item.completed = !item.completed;
+        }
+    }
+
+    /// Removes the item with the given id, if present
+    pub fn remove(&mut self, id: Uuid) {
+        self.items.retain(|item| item.id != id);
+    }
+
+    /// Replaces the title of the item with the given id
+    pub fn set_title(&mut self, id: Uuid, title: String) {
+        if let Some(item) = self.items.iter_mut().find(|item| item.id == id) {
+            item.title = title;
+        }
+    }
+
+    /// Drops every completed item from the list
+    pub fn clear_completed(&mut self) {
+        self.items.retain(|item| !item.completed);
+    }
+
+    /// The number of items that are not yet completed
+    pub fn remaining(&self) -> usize {
+        self.items.iter().filter(|item| !item.completed).count()
+    }
+
+    /// Whether the list holds no items
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the items matching the given visibility `filter`
+    pub fn filtered(&self, filter: Filter) -> Vec<TodoItem> {
+        self.items
+            .iter()
+            .filter(|item| match filter {
+                Filter::All => true,
+                Filter::Active => !item.completed,
+                Filter::Completed => item.completed,
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// Which subset of todos is currently visible
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Filter {
+    #[default]
+    All,
+    Active,
+    Completed,
+}
+
+impl Filter {
+    /// Parses a filter from a URL hash such as `#/active`
+    pub fn from_hash(hash: &str) -> Self {
+        match hash {
+            "#/active" => Filter::Active,
+            "#/completed" => Filter::Completed,
+            _ => Filter::All,
+        }
+    }
+
+    /// The URL hash representing this filter
+    pub fn to_hash(self) -> &'static str {
+        match self {
+            Filter::All => "#/",
+            Filter::Active => "#/active",
+            Filter::Completed => "#/completed",
+        }
+    }
+}
+
+/// The full application state: the todo items and the active filter
+#[derive(Clone, Debug, Default)]
+pub struct State {
+    pub todos: TodoList,
+    pub filter: Filter,
+}
+
+/// Every state transition the application can make
+#[derive(Clone, Debug)]
+pub enum Action {
+    Add(String),
+    Toggle(Uuid),
+    Remove(Uuid),
+    Edit(Uuid, String),
+    ClearCompleted,
+    SetFilter(Filter),
+}
+
+/// Pure state transition function
+///
+/// Produces the next [`State`] from the current one and an [`Action`]
+/// without touching any signals, so it can be unit-tested in isolation.
+pub fn reducer(state: &State, action: Action) -> State {
+    let mut next = state.clone();
+    match action {
+        Action::Add(title) => {
+            next.todos.add_item(title);
+        }
+        Action::Toggle(id) => next.todos.toggle(id),
+        Action::Remove(id) => next.todos.remove(id),
+        Action::Edit(id, title) => next.todos.set_title(id, title),
+        Action::ClearCompleted => next.todos.clear_completed(),
+        Action::SetFilter(filter) => next.filter = filter,
+    }
+    next
+}
+
+/// Errors surfaced by the [`TodoListHandle`] binding layer
+#[derive(Clone, Debug, PartialEq, Eq, uniffi::Error)]
+pub enum TodoError {
+    /// No todo item carries the requested id
+    TodoDoesNotExist,
+}
+
+impl std::fmt::Display for TodoError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TodoError::TodoDoesNotExist => f.write_str("no todo exists with the given id"),
+        }
+    }
+}
+
+impl std::error::Error for TodoError {}
+
+/// Thread-safe handle exposing the todo engine to foreign callers
+///
+/// The list lives behind an `Arc<RwLock<_>>` so the identical Rust logic
+/// can be driven concurrently from native mobile/desktop bindings.
+#[derive(uniffi::Object)]
+pub struct TodoListHandle {
+    inner: Arc<RwLock<TodoList>>,
+}
+
+#[uniffi::export]
+impl TodoListHandle {
+    /// Creates an empty handle
+    #[uniffi::constructor]
+    pub fn new() -> Arc<Self> {
+        Arc::new(TodoListHandle {
+            inner: Arc::new(RwLock::new(TodoList::new())),
+        })
+    }
+
+    /// Adds an item and returns it
+    pub fn add(&self, title: String) -> TodoItem {
+        self.inner.write().unwrap().add_item(title)
+    }
+
+    /// Toggles the completion of the item with `id`
+    pub fn toggle(&self, id: Uuid) -> Result<(), TodoError> {
+        let mut list = self.inner.write().unwrap();
+        if list.items.iter().any(|item| item.id == id) {
+            list.toggle(id);
+            Ok(())
+        } else {
+            Err(TodoError::TodoDoesNotExist)
+        }
+    }
+
+    /// Removes the item with `id`
+    pub fn remove(&self, id: Uuid) -> Result<(), TodoError> {
+        let mut list = self.inner.write().unwrap();
+        if list.items.iter().any(|item| item.id == id) {
+            list.remove(id);
+            Ok(())
+        } else {
+            Err(TodoError::TodoDoesNotExist)
+        }
+    }
+
+    /// Edits the title of the item with `id`
+    pub fn edit(&self, id: Uuid, title: String) -> Result<(), TodoError> {
+        let mut list = self.inner.write().unwrap();
+        if list.items.iter().any(|item| item.id == id) {
+            list.set_title(id, title);
+            Ok(())
+        } else {
+            Err(TodoError::TodoDoesNotExist)
+        }
+    }
+
+    /// The number of items that are not yet completed
+    pub fn remaining(&self) -> u64 {
+        self.inner.read().unwrap().remaining() as u64
+    }
+
+    /// Snapshot of every item in the list
+    ///
+    /// An empty list is a normal state and yields an empty vector.
+    pub fn items(&self) -> Vec<TodoItem> {
+        self.inner.read().unwrap().items.clone()
+    }
+}
+
+// Maps `uuid::Uuid` to a `string` across the UniFFI boundary.
+uniffi::custom_type!(Uuid, String);
+
+impl UniffiCustomTypeConverter for Uuid {
+    type Builtin = String;
+
+    fn into_custom(val: String) -> uniffi::Result<Self> {
+        Ok(Uuid::parse_str(&val)?)
+    }
+
+    fn from_custom(obj: Self) -> String {
+        obj.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_item_appends_and_sets_defaults() {
+        let mut list = TodoList::new();
+        let item = list.add_item("write tests".to_string());
+        assert_eq!(list.items.len(), 1);
+        assert_eq!(item.title, "write tests");
+        assert!(!item.completed);
+    }
+
+    #[test]
+    fn toggle_flips_only_the_matching_item() {
+        let mut list = TodoList::new();
+        let a = list.add_item("a".to_string()).id;
+        let b = list.add_item("b".to_string()).id;
+        list.toggle(a);
+        assert!(list.items.iter().find(|i| i.id == a).unwrap().completed);
+        assert!(!list.items.iter().find(|i| i.id == b).unwrap().completed);
+    }
+
+    #[test]
+    fn remove_drops_the_matching_item() {
+        let mut list = TodoList::new();
+        let id = list.add_item("doomed".to_string()).id;
+        list.remove(id);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn set_title_renames_in_place() {
+        let mut list = TodoList::new();
+        let id = list.add_item("old".to_string()).id;
+        list.set_title(id, "new".to_string());
+        assert_eq!(list.items[0].title, "new");
+    }
+
+    #[test]
+    fn clear_completed_and_remaining() {
+        let mut list = TodoList::new();
+        let done = list.add_item("done".to_string()).id;
+        list.add_item("todo".to_string());
+        list.toggle(done);
+        assert_eq!(list.remaining(), 1);
+        list.clear_completed();
+        assert_eq!(list.items.len(), 1);
+        assert_eq!(list.items[0].title, "todo");
+    }
+
+    #[test]
+    fn filtered_partitions_by_completion() {
+        let mut list = TodoList::new();
+        let done = list.add_item("done".to_string()).id;
+        list.add_item("todo".to_string());
+        list.toggle(done);
+        assert_eq!(list.filtered(Filter::All).len(), 2);
+        assert_eq!(list.filtered(Filter::Active)[0].title, "todo");
+        assert_eq!(list.filtered(Filter::Completed)[0].title, "done");
+    }
+
+    #[test]
+    fn reducer_add_appends() {
+        let state = reducer(&State::default(), Action::Add("a".to_string()));
+        assert_eq!(state.todos.items.len(), 1);
+    }
+
+    #[test]
+    fn reducer_toggle_remove_and_edit_target_by_id() {
+        let state = reducer(&State::default(), Action::Add("a".to_string()));
+        let id = state.todos.items[0].id;
+
+        let toggled = reducer(&state, Action::Toggle(id));
+        assert!(toggled.todos.items[0].completed);
+
+        let edited = reducer(&state, Action::Edit(id, "b".to_string()));
+        assert_eq!(edited.todos.items[0].title, "b");
+
+        let removed = reducer(&state, Action::Remove(id));
+        assert!(removed.todos.is_empty());
+    }
+
+    #[test]
+    fn reducer_clear_completed_keeps_active() {
+        let mut state = reducer(&State::default(), Action::Add("keep".to_string()));
+        state = reducer(&state, Action::Add("drop".to_string()));
+        let drop = state.todos.items[1].id;
+        state = reducer(&state, Action::Toggle(drop));
+        state = reducer(&state, Action::ClearCompleted);
+        assert_eq!(state.todos.items.len(), 1);
+        assert_eq!(state.todos.items[0].title, "keep");
+    }
+
+    #[test]
+    fn reducer_set_filter_updates_filter_only() {
+        let state = reducer(&State::default(), Action::SetFilter(Filter::Completed));
+        assert_eq!(state.filter, Filter::Completed);
+        assert!(state.todos.is_empty());
+    }
+}
@@ -0,0 +1,21 @@
+//! Todo crate root.
+//!
+//! The [`engine`] module is the platform-agnostic core: it compiles with no
+//! dependency on `leptos` and powers the UniFFI bindings. The [`web`] module
+//! holds the Leptos UI and is only compiled when the `leptos` feature is
+//! enabled, keeping the core buildable for native (non-web) consumers.
+
+// The UniFFI scaffolding generated by `include_scaffolding!` trips this
+// lint on its own doc comments; allow it for the generated code.
+#![allow(clippy::empty_line_after_doc_comments)]
+
+pub mod engine;
+
+#[cfg(feature = "leptos")]
+pub mod web;
+
+pub use engine::{
+    reducer, Action, Filter, State, TodoError, TodoItem, TodoList, TodoListHandle,
+};
+
+uniffi::include_scaffolding!("todo");
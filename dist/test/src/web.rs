@@ -0,0 +1,214 @@
+//! Leptos UI layer, compiled only under the `leptos` feature.
+
+use leptos::*;
+use uuid::Uuid;
+
+use crate::engine::{reducer, Action, Filter, State, TodoItem, TodoList};
+
+/// Persistence of the todo list to the browser's `localStorage`
+mod storage {
+    use super::{TodoItem, TodoList};
+    use leptos::window;
+    use serde::{Deserialize, Serialize};
+
+    /// The `localStorage` key under which the todo list is persisted
+    pub const STORAGE_KEY: &str = "todos-leptos";
+
+    /// On-the-wire representation of a [`TodoList`]
+    ///
+    /// Only the fields that need to survive a reload are serialized; the
+    /// running list is reconstructed from these on load.
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct TodoSerialized {
+        pub items: Vec<TodoItem>,
+    }
+
+    impl From<&TodoList> for TodoSerialized {
+        fn from(list: &TodoList) -> Self {
+            TodoSerialized {
+                items: list.items.clone(),
+            }
+        }
+    }
+
+    impl TodoList {
+        /// Loads a todo list from `localStorage`, falling back to an empty
+        /// list when storage is unavailable or holds no (valid) data
+        pub fn load_from_storage() -> Self {
+            let Ok(Some(storage)) = window().local_storage() else {
+                return TodoList::new();
+            };
+            let Ok(Some(raw)) = storage.get_item(STORAGE_KEY) else {
+                return TodoList::new();
+            };
+            match serde_json::from_str::<TodoSerialized>(&raw) {
+                Ok(data) => TodoList { items: data.items },
+                Err(_) => TodoList::new(),
+            }
+        }
+
+        /// Serializes the list to JSON and writes it to `localStorage`
+        ///
+        /// Silently degrades to a no-op when storage is unavailable.
+        pub fn save_to_storage(&self) {
+            let Ok(Some(storage)) = window().local_storage() else {
+                return;
+            };
+            if let Ok(json) = serde_json::to_string(&TodoSerialized::from(self)) {
+                let _ = storage.set_item(STORAGE_KEY, &json);
+            }
+        }
+    }
+}
+
+/// Main component for the todo application
+#[component]
+pub fn TodoApp() -> impl IntoView {
+    // Seed the store from storage (items) and the URL hash (filter) so a
+    // filtered view can be bookmarked and the list survives a reload
+    let initial = State {
+        todos: TodoList::load_from_storage(),
+        filter: window()
+            .location()
+            .hash()
+            .map(|h| Filter::from_hash(&h))
+            .unwrap_or_default(),
+    };
+    let (state, set_state) = create_signal(initial);
+
+    // Single entry point for every transition: run the pure reducer and
+    // store its result. View elements dispatch actions rather than mutate.
+    let dispatch = Callback::new(move |action: Action| {
+        set_state.update(|s| *s = reducer(s, action));
+    });
+
+    // Persist the list and mirror the filter into the URL hash on change
+    create_effect(move |_| {
+        state.with(|s| s.todos.save_to_storage());
+    });
+    create_effect(move |_| {
+        let _ = window()
+            .location()
+            .set_hash(state.with(|s| s.filter).to_hash());
+    });
+
+    // Id of the item currently being edited, if any
+    let (editing, set_editing) = create_signal(None::<Uuid>);
+
+    view! {
+        <div class="todo-app">
+            <h1>"Leptos Todo App"</h1>
+            <div class="todo-input">
+                <input
+                    type="text"
+                    placeholder="What needs to be done?"
+                    on:keypress=move |ev| {
+                        if ev.key() == "Enter" {
+                            let input = event_target_value(&ev);
+                            if !input.trim().is_empty() {
+                                dispatch.call(Action::Add(input));
+                            }
+                        }
+                    }
+                />
+            </div>
+            <ul class="todo-list">
+                <For
+                    each=move || state.with(|s| s.todos.filtered(s.filter))
+                    key=|todo| todo.id
+                    children=move |todo| {
+                        let id = todo.id;
+                        let title = todo.title.clone();
+                        let edit_title = title.clone();
+                        view! {
+                            <li class="todo-item">
+                                <input
+                                    type="checkbox"
+                                    checked=todo.completed
+                                    on:change=move |_| dispatch.call(Action::Toggle(id))
+                                />
+                                <Show
+                                    when=move || editing.get() == Some(id)
+                                    fallback=move || {
+                                        let title = title.clone();
+                                        view! {
+                                            <span on:dblclick=move |_| set_editing.set(Some(id))>
+                                                {title.clone()}
+                                            </span>
+                                        }
+                                    }
+                                >
+                                    <input
+                                        type="text"
+                                        class="edit"
+                                        prop:value=edit_title.clone()
+                                        on:keypress=move |ev| {
+                                            if ev.key() == "Enter" {
+                                                let title = event_target_value(&ev);
+                                                dispatch.call(Action::Edit(id, title));
+                                                set_editing.set(None);
+                                            }
+                                        }
+                                        on:blur=move |ev| {
+                                            let title = event_target_value(&ev);
+                                            dispatch.call(Action::Edit(id, title));
+                                            set_editing.set(None);
+                                        }
+                                    />
+                                </Show>
+                                <button
+                                    class="destroy"
+                                    on:click=move |_| dispatch.call(Action::Remove(id))
+                                >
+                                    "x"
+                                </button>
+                            </li>
+                        }
+                    }
+                />
+            </ul>
+            <footer class="footer">
+                <span class="todo-count">
+                    {move || format!("{} items left", state.with(|s| s.todos.remaining()))}
+                </span>
+                <ul class="filters">
+                    <li>
+                        <button
+                            class:selected=move || state.with(|s| s.filter) == Filter::All
+                            on:click=move |_| dispatch.call(Action::SetFilter(Filter::All))
+                        >
+                            "All"
+                        </button>
+                    </li>
+                    <li>
+                        <button
+                            class:selected=move || state.with(|s| s.filter) == Filter::Active
+                            on:click=move |_| dispatch.call(Action::SetFilter(Filter::Active))
+                        >
+                            "Active"
+                        </button>
+                    </li>
+                    <li>
+                        <button
+                            class:selected=move || state.with(|s| s.filter) == Filter::Completed
+                            on:click=move |_| dispatch.call(Action::SetFilter(Filter::Completed))
+                        >
+                            "Completed"
+                        </button>
+                    </li>
+                </ul>
+                <button
+                    class="clear-completed"
+                    on:click=move |_| dispatch.call(Action::ClearCompleted)
+                >
+                    "Clear completed"
+                </button>
+            </footer>
+        </div>
+    }
+}
+
+/// Mounts the [`TodoApp`] component to the document body
+pub fn mount() {
+    mount_to_body(|| view! { <TodoApp/> })
+}